@@ -1,15 +1,20 @@
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow, bail};
 use async_openai::{
     Client,
     config::OpenAIConfig,
+    error::OpenAIError,
     types::audio::{AudioInput, CreateTranscriptionRequestArgs},
 };
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use serde::Deserialize;
 use tokio::runtime::Runtime;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Inspect and chunk audio files with ffmpeg")]
@@ -18,6 +23,45 @@ struct Cli {
     command: Commands,
 }
 
+/// Audio codec to re-encode chunks with, collapsing speech into far fewer, smaller pieces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum Codec {
+    Opus,
+    Mp3,
+    Aac,
+}
+
+impl Codec {
+    /// ffmpeg encoder name passed to `-c:a`.
+    fn encoder(&self) -> &'static str {
+        match self {
+            Codec::Opus => "libopus",
+            Codec::Mp3 => "libmp3lame",
+            Codec::Aac => "aac",
+        }
+    }
+
+    /// Container/file extension for the re-encoded output.
+    fn extension(&self) -> &'static str {
+        match self {
+            Codec::Opus => "opus",
+            Codec::Mp3 => "mp3",
+            Codec::Aac => "m4a",
+        }
+    }
+}
+
+/// Transcription engine to run a chunk through.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum Backend {
+    /// The hosted OpenAI transcription API.
+    Openai,
+    /// A local `whisper`/`whisper.cpp` CLI invoked as a subprocess.
+    Whisper,
+    /// An OpenAI-compatible endpoint selected with `--base-url`.
+    Custom,
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Display ffmpeg metadata for an input file
@@ -32,6 +76,27 @@ enum Commands {
         /// Maximum chunk size (in megabytes)
         #[arg(long, default_value_t = 25.0)]
         max_size_mb: f64,
+        /// Snap chunk boundaries to detected silence so cuts avoid landing mid-word
+        #[arg(long)]
+        snap_to_silence: bool,
+        /// Noise floor (in dB) below which audio counts as silence
+        #[arg(long, default_value_t = -30.0)]
+        silence_noise_db: f64,
+        /// Minimum silence length (seconds) for `silencedetect` to report an interval
+        #[arg(long, default_value_t = 0.5)]
+        min_silence: f64,
+        /// How many seconds a snapped cut may move earlier than the budget cut
+        #[arg(long, default_value_t = 5.0)]
+        max_drift: f64,
+        /// Re-encode each chunk with this codec instead of stream-copying the source
+        #[arg(long, value_enum)]
+        recode: Option<Codec>,
+        /// Target audio bitrate (kbps) used for planning and encoding when --recode is set
+        #[arg(long, default_value_t = 32.0)]
+        target_bitrate_kbps: f64,
+        /// Overlap consecutive chunks by this many seconds so `stitch` can recover clipped words
+        #[arg(long, default_value_t = 0.0)]
+        overlap: f64,
     },
     /// Split an already compliant chunk into N sequential parts
     Split {
@@ -41,10 +106,77 @@ enum Commands {
         #[arg(long)]
         parts: usize,
     },
-    /// Transcribe a chunked audio file using OpenAI
+    /// Transcribe a chunked audio file using the selected backend
     Transcribe {
         /// Audio chunk to transcribe
         input: PathBuf,
+        /// Transcription backend to use
+        #[arg(long, value_enum, default_value_t = Backend::Openai)]
+        backend: Backend,
+        /// Model name (OpenAI/custom) or model path (whisper); a backend default is used when unset
+        #[arg(long)]
+        model: Option<String>,
+        /// Spoken-language hint (ISO 639-1 code)
+        #[arg(long)]
+        language: Option<String>,
+        /// Sampling temperature for the OpenAI/custom backends
+        #[arg(long)]
+        temperature: Option<f32>,
+        /// Base URL of an OpenAI-compatible endpoint (required by `--backend custom`)
+        #[arg(long)]
+        base_url: Option<String>,
+        /// Executable invoked for the whisper backend
+        #[arg(long, default_value = "whisper")]
+        whisper_bin: String,
+        /// Reject (rather than buffer) a chunk whose in-memory footprint would exceed this many MB
+        #[arg(long, default_value_t = 64.0)]
+        max_memory_mb: f64,
+    },
+    /// Merge per-chunk transcripts back into one document, de-duplicating overlap regions
+    Stitch {
+        /// Original audio file whose `*_chunk000.*` transcripts should be merged
+        input: PathBuf,
+        /// Where to write the merged transcript (defaults to `<stem>.stitched.txt`)
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Overlap (seconds) the chunks were created with; sizes the de-duplication window
+        #[arg(long, default_value_t = 0.0)]
+        overlap: f64,
+        /// Prefix each merged segment with its `[HH:MM:SS]` start offset
+        #[arg(long)]
+        timestamps: bool,
+    },
+    /// Transcribe every chunk discovered from one input over a retry-aware worker pool
+    TranscribeAll {
+        /// Original audio file whose `*_chunk000.*` pieces should be transcribed
+        input: PathBuf,
+        /// Maximum number of chunks transcribed concurrently
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+        /// Maximum attempts per chunk before the run is considered failed
+        #[arg(long, default_value_t = 5)]
+        max_tries: u32,
+        /// Transcription backend to use
+        #[arg(long, value_enum, default_value_t = Backend::Openai)]
+        backend: Backend,
+        /// Model name (OpenAI/custom) or model path (whisper); a backend default is used when unset
+        #[arg(long)]
+        model: Option<String>,
+        /// Spoken-language hint (ISO 639-1 code)
+        #[arg(long)]
+        language: Option<String>,
+        /// Sampling temperature for the OpenAI/custom backends
+        #[arg(long)]
+        temperature: Option<f32>,
+        /// Base URL of an OpenAI-compatible endpoint (required by `--backend custom`)
+        #[arg(long)]
+        base_url: Option<String>,
+        /// Executable invoked for the whisper backend
+        #[arg(long, default_value = "whisper")]
+        whisper_bin: String,
+        /// Reject (rather than buffer) a chunk whose in-memory footprint would exceed this many MB
+        #[arg(long, default_value_t = 64.0)]
+        max_memory_mb: f64,
     },
 }
 
@@ -53,9 +185,78 @@ fn main() -> Result<()> {
 
     match cli.command {
         Commands::Inspect { input } => inspect_audio(&input),
-        Commands::Chunk { input, max_size_mb } => chunk_audio(&input, max_size_mb),
+        Commands::Chunk {
+            input,
+            max_size_mb,
+            snap_to_silence,
+            silence_noise_db,
+            min_silence,
+            max_drift,
+            recode,
+            target_bitrate_kbps,
+            overlap,
+        } => chunk_audio(
+            &input,
+            max_size_mb,
+            snap_to_silence,
+            silence_noise_db,
+            min_silence,
+            max_drift,
+            recode,
+            target_bitrate_kbps,
+            overlap,
+        ),
         Commands::Split { input, parts } => split_chunk(&input, parts),
-        Commands::Transcribe { input } => transcribe_chunk(&input),
+        Commands::Transcribe {
+            input,
+            backend,
+            model,
+            language,
+            temperature,
+            base_url,
+            whisper_bin,
+            max_memory_mb,
+        } => transcribe_chunk(
+            &input,
+            backend,
+            model,
+            language,
+            temperature,
+            base_url,
+            whisper_bin,
+            max_memory_mb,
+        ),
+        Commands::Stitch {
+            input,
+            output,
+            overlap,
+            timestamps,
+        } => stitch_transcripts_command(&input, output.as_deref(), overlap, timestamps),
+        Commands::TranscribeAll {
+            input,
+            concurrency,
+            max_tries,
+            backend,
+            model,
+            language,
+            temperature,
+            base_url,
+            whisper_bin,
+            max_memory_mb,
+        } => transcribe_all(
+            &input,
+            concurrency,
+            max_tries,
+            build_transcriber(
+                backend,
+                model,
+                language,
+                temperature,
+                base_url,
+                whisper_bin,
+                mb_to_bytes(max_memory_mb),
+            )?,
+        ),
     }
 }
 
@@ -82,28 +283,62 @@ fn inspect_audio(input: &Path) -> Result<()> {
     Ok(())
 }
 
-fn chunk_audio(input: &Path, max_size_mb: f64) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+fn chunk_audio(
+    input: &Path,
+    max_size_mb: f64,
+    snap_to_silence: bool,
+    silence_noise_db: f64,
+    min_silence: f64,
+    max_drift: f64,
+    recode: Option<Codec>,
+    target_bitrate_kbps: f64,
+    overlap: f64,
+) -> Result<()> {
     if max_size_mb <= 0.0 {
         bail!("max_size_mb must be greater than zero");
     }
 
     ensure_input_exists(input)?;
     let metadata = fetch_audio_metadata(input)?;
-    let plan = calculate_chunk_plan(
-        metadata.duration_seconds,
-        metadata.bitrate_kbps,
-        max_size_mb,
-    )?;
+
+    // When re-encoding, size is dictated by the target bitrate, not the (usually larger) source.
+    let plan_bitrate = match recode {
+        Some(_) => {
+            if target_bitrate_kbps <= 0.0 {
+                bail!("target_bitrate_kbps must be greater than zero");
+            }
+            target_bitrate_kbps
+        }
+        None => metadata.bitrate_kbps,
+    };
+
+    let plan = if snap_to_silence {
+        let silences = detect_silences(input, silence_noise_db, min_silence)?;
+        calculate_chunk_plan_snapped(
+            metadata.duration_seconds,
+            plan_bitrate,
+            max_size_mb,
+            &silences,
+            max_drift,
+            overlap,
+        )?
+    } else {
+        calculate_chunk_plan(metadata.duration_seconds, plan_bitrate, max_size_mb, overlap)?
+    };
 
     let parent = input.parent().unwrap_or_else(|| Path::new("."));
     let base_name = input
         .file_stem()
         .map(|stem| stem.to_string_lossy().to_string())
         .unwrap_or_else(|| "chunk".to_string());
-    let extension = input
-        .extension()
-        .map(|ext| format!(".{}", ext.to_string_lossy()))
-        .unwrap_or_default();
+    let extension = match recode {
+        Some(codec) => format!(".{}", codec.extension()),
+        None => input
+            .extension()
+            .map(|ext| format!(".{}", ext.to_string_lossy()))
+            .unwrap_or_default(),
+    };
 
     for (index, (start, duration)) in plan.iter().enumerate() {
         let output_name = format!("{base_name}_chunk{index:03}{extension}");
@@ -111,7 +346,8 @@ fn chunk_audio(input: &Path, max_size_mb: f64) -> Result<()> {
         let start_arg = format!("{start:.3}");
         let duration_arg = format!("{duration:.3}");
 
-        let status = Command::new("ffmpeg")
+        let mut command = Command::new("ffmpeg");
+        command
             .arg("-hide_banner")
             .arg("-loglevel")
             .arg("error")
@@ -121,9 +357,26 @@ fn chunk_audio(input: &Path, max_size_mb: f64) -> Result<()> {
             .arg("-ss")
             .arg(&start_arg)
             .arg("-t")
-            .arg(&duration_arg)
-            .arg("-c")
-            .arg("copy")
+            .arg(&duration_arg);
+
+        match recode {
+            // Re-encode down to a mono, low-bitrate stream to pack speech into fewer chunks.
+            Some(codec) => {
+                command
+                    .arg("-c:a")
+                    .arg(codec.encoder())
+                    .arg("-b:a")
+                    .arg(format!("{target_bitrate_kbps}k"))
+                    .arg("-ac")
+                    .arg("1");
+            }
+            // Default fast path: stream-copy the source without transcoding.
+            None => {
+                command.arg("-c").arg("copy");
+            }
+        }
+
+        let status = command
             .arg(&output_path)
             .status()
             .with_context(|| format!("failed to chunk file while creating {output_name}"))?;
@@ -132,6 +385,12 @@ fn chunk_audio(input: &Path, max_size_mb: f64) -> Result<()> {
             bail!("ffmpeg failed to create {output_name}");
         }
 
+        // Recoded outputs are validated against the same guard the split path uses.
+        if recode.is_some() {
+            ensure_chunk_within_limit(&output_path)
+                .with_context(|| format!("{output_name} exceeded the 25 MB limit"))?;
+        }
+
         println!(
             "Created {output_name} (start: {:.3}s, duration: {:.3}s)",
             start, duration
@@ -210,12 +469,33 @@ const CHUNK_DURATION_BUFFER_SECONDS: f64 = 100.0;
 const PLANNED_MAX_CHUNK_DURATION_SECONDS: f64 =
     OPENAI_MAX_TRANSCRIPTION_DURATION_SECONDS - CHUNK_DURATION_BUFFER_SECONDS;
 
-fn transcribe_chunk(input: &Path) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+fn transcribe_chunk(
+    input: &Path,
+    backend: Backend,
+    model: Option<String>,
+    language: Option<String>,
+    temperature: Option<f32>,
+    base_url: Option<String>,
+    whisper_bin: String,
+    max_memory_mb: f64,
+) -> Result<()> {
     ensure_input_exists(input)?;
     ensure_chunk_within_limit(input)?;
     ensure_chunk_duration_within_limit(input)?;
-    let api_key = load_openai_api_key()?;
-    let transcript = transcribe_chunk_with_openai(input, api_key)?;
+
+    let transcriber = build_transcriber(
+        backend,
+        model,
+        language,
+        temperature,
+        base_url,
+        whisper_bin,
+        mb_to_bytes(max_memory_mb),
+    )?;
+
+    let runtime = Runtime::new().context("failed to start tokio runtime")?;
+    let transcript = runtime.block_on(transcriber.transcribe(input))?;
 
     let output_path = transcript_output_path(input);
     std::fs::write(&output_path, transcript).with_context(|| {
@@ -229,6 +509,329 @@ fn transcribe_chunk(input: &Path) -> Result<()> {
     Ok(())
 }
 
+const RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// Convert a megabyte budget into bytes, clamped at zero.
+fn mb_to_bytes(mb: f64) -> u64 {
+    (mb.max(0.0) * 1024.0 * 1024.0) as u64
+}
+
+/// Discover every chunk produced from `input`, then transcribe them over a bounded-concurrency
+/// pool on a single shared runtime, retrying transient failures and skipping already-done chunks.
+fn transcribe_all(
+    input: &Path,
+    concurrency: usize,
+    max_tries: u32,
+    transcriber: Transcribers,
+) -> Result<()> {
+    if concurrency == 0 {
+        bail!("concurrency must be at least 1");
+    }
+    if max_tries == 0 {
+        bail!("max_tries must be at least 1");
+    }
+
+    ensure_input_exists(input)?;
+    let chunks = discover_chunks(input)?;
+    if chunks.is_empty() {
+        bail!(
+            "no chunks found for '{}'; run `chunk` first",
+            input.to_string_lossy()
+        );
+    }
+
+    let runtime = Runtime::new().context("failed to start tokio runtime")?;
+    runtime.block_on(transcribe_chunks(
+        chunks,
+        Arc::new(transcriber),
+        concurrency,
+        max_tries,
+    ))
+}
+
+/// Find the `<stem>_chunk*` files that live alongside `input`, sorted by name.
+fn discover_chunks(input: &Path) -> Result<Vec<PathBuf>> {
+    let parent = input
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let base_name = input
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "chunk".to_string());
+    let prefix = format!("{base_name}_chunk");
+
+    let entries = std::fs::read_dir(parent)
+        .with_context(|| format!("failed to read directory '{}'", parent.display()))?;
+
+    let mut chunks = Vec::new();
+    for entry in entries {
+        let entry = entry
+            .with_context(|| format!("failed to read a directory entry in '{}'", parent.display()))?;
+        let path = entry.path();
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => continue,
+        };
+        // Skip previously written transcripts; only the audio pieces are transcribed.
+        if name.starts_with(&prefix) && !name.ends_with(".txt") {
+            chunks.push(path);
+        }
+    }
+
+    chunks.sort();
+    Ok(chunks)
+}
+
+async fn transcribe_chunks(
+    chunks: Vec<PathBuf>,
+    transcriber: Arc<Transcribers>,
+    concurrency: usize,
+    max_tries: u32,
+) -> Result<()> {
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+
+    let mut set = JoinSet::new();
+    for chunk in chunks {
+        let output_path = transcript_output_path(&chunk);
+        if output_path.exists() {
+            println!(
+                "Skipping '{}', transcript already exists",
+                chunk.to_string_lossy()
+            );
+            continue;
+        }
+
+        let transcriber = Arc::clone(&transcriber);
+        let semaphore = Arc::clone(&semaphore);
+        set.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let result = transcribe_chunk_with_retries(&transcriber, &chunk, max_tries).await;
+            (chunk, output_path, result)
+        });
+    }
+
+    let mut failures = Vec::new();
+    while let Some(joined) = set.join_next().await {
+        let (chunk, output_path, result) = joined.context("a transcription task panicked")?;
+        match result {
+            Ok(transcript) => {
+                std::fs::write(&output_path, transcript).with_context(|| {
+                    format!(
+                        "failed to write transcript to '{}'",
+                        output_path.to_string_lossy()
+                    )
+                })?;
+                println!("Transcript saved to '{}'", output_path.to_string_lossy());
+            }
+            Err(error) => {
+                eprintln!("Failed to transcribe '{}': {error:#}", chunk.to_string_lossy());
+                failures.push(chunk.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "{} chunk(s) failed to transcribe: {}",
+            failures.len(),
+            failures.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Transcribe a single chunk through the selected backend, retrying transient failures with
+/// exponential backoff.
+async fn transcribe_chunk_with_retries(
+    transcriber: &Transcribers,
+    chunk: &Path,
+    max_tries: u32,
+) -> Result<String> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match transcriber.transcribe(chunk).await {
+            Ok(transcript) => return Ok(transcript),
+            Err(error) if attempt < max_tries && is_transient_error(&error) => {
+                let backoff = retry_backoff(attempt);
+                eprintln!(
+                    "Attempt {attempt} for '{}' failed ({error:#}); retrying in {:.1}s",
+                    chunk.to_string_lossy(),
+                    backoff.as_secs_f64()
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            Err(error) => {
+                return Err(error).with_context(|| {
+                    format!("transcription failed for '{}'", chunk.to_string_lossy())
+                });
+            }
+        }
+    }
+}
+
+/// Whether a backend error is worth retrying. Only OpenAI-originated failures carry retry
+/// semantics; other backends (e.g. a local whisper subprocess) are treated as terminal.
+fn is_transient_error(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<OpenAIError>()
+        .is_some_and(is_transient_openai_error)
+}
+
+/// Transient OpenAI failures worth retrying: network errors plus rate limits and 5xx responses.
+fn is_transient_openai_error(error: &OpenAIError) -> bool {
+    match error {
+        OpenAIError::Reqwest(_) => true,
+        OpenAIError::ApiError(api) => {
+            let kind = api.r#type.as_deref().unwrap_or_default();
+            let code = api.code.as_deref().unwrap_or_default();
+            kind == "server_error" || kind.contains("rate_limit") || code == "rate_limit_exceeded"
+        }
+        _ => false,
+    }
+}
+
+/// Exponential backoff capped so a long retry budget cannot sleep unboundedly.
+fn retry_backoff(attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(6);
+    Duration::from_millis(RETRY_BASE_DELAY_MS * (1u64 << exponent))
+}
+
+/// Rough speech rate used to turn an overlap (in seconds) into a token-count de-dup window.
+const STITCH_TOKENS_PER_SECOND: f64 = 3.0;
+
+/// Read each chunk's transcript in order and merge them into a single document, splicing away the
+/// words repeated across overlapping windows.
+fn stitch_transcripts_command(
+    input: &Path,
+    output: Option<&Path>,
+    overlap: f64,
+    timestamps: bool,
+) -> Result<()> {
+    if overlap < 0.0 {
+        bail!("overlap must not be negative");
+    }
+
+    ensure_input_exists(input)?;
+    let chunks = discover_chunks(input)?;
+    if chunks.is_empty() {
+        bail!(
+            "no chunks found for '{}'; run `chunk` first",
+            input.to_string_lossy()
+        );
+    }
+
+    let mut segments = Vec::with_capacity(chunks.len());
+    let mut offset = 0.0;
+    for chunk in &chunks {
+        let transcript_path = transcript_output_path(chunk);
+        let text = std::fs::read_to_string(&transcript_path).with_context(|| {
+            format!(
+                "missing transcript '{}'; run `transcribe-all {}` first",
+                transcript_path.to_string_lossy(),
+                input.to_string_lossy()
+            )
+        })?;
+        segments.push((offset, text));
+        // Advance by the chunk's real length minus the overlap so offsets track the source timeline.
+        let metadata = fetch_audio_metadata(chunk)?;
+        offset += metadata.duration_seconds - overlap;
+    }
+
+    let merged = stitch_transcripts(&segments, overlap, timestamps);
+
+    let output_path = match output {
+        Some(path) => path.to_path_buf(),
+        None => {
+            let parent = input.parent().unwrap_or_else(|| Path::new("."));
+            let base_name = input
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| "transcript".to_string());
+            parent.join(format!("{base_name}.stitched.txt"))
+        }
+    };
+
+    std::fs::write(&output_path, merged).with_context(|| {
+        format!(
+            "failed to write merged transcript to '{}'",
+            output_path.to_string_lossy()
+        )
+    })?;
+
+    println!("Merged transcript saved to '{}'", output_path.to_string_lossy());
+    Ok(())
+}
+
+/// Merge `(start_offset, transcript)` segments in order, removing the overlap-region duplication
+/// between each pair by splicing where the tail of one segment matches the head of the next.
+fn stitch_transcripts(segments: &[(f64, String)], overlap_seconds: f64, timestamps: bool) -> String {
+    let window = (overlap_seconds * STITCH_TOKENS_PER_SECOND).ceil().max(0.0) as usize;
+
+    let mut pieces = Vec::with_capacity(segments.len());
+    let mut previous: Vec<String> = Vec::new();
+    for (offset, text) in segments {
+        let display = tokenize(text);
+        let normalized: Vec<String> = display.iter().map(|t| normalize_token(t)).collect();
+
+        let shared = if previous.is_empty() {
+            0
+        } else {
+            longest_overlap(&previous, &normalized, window)
+        };
+
+        let mut piece = display[shared..].join(" ");
+        if timestamps {
+            piece = format!("[{}] {}", format_timestamp(*offset), piece);
+        }
+        pieces.push(piece);
+        previous = normalized;
+    }
+
+    pieces.join("\n")
+}
+
+/// Split text into whitespace-delimited tokens.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace().map(|t| t.to_string()).collect()
+}
+
+/// Normalize a token for comparison: lower-cased, with surrounding punctuation removed.
+fn normalize_token(token: &str) -> String {
+    token
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Largest `k <= window` where the last `k` tokens of `tail` equal the first `k` of `head`.
+fn longest_overlap(tail: &[String], head: &[String], window: usize) -> usize {
+    let max_k = tail.len().min(head.len()).min(window);
+    for k in (1..=max_k).rev() {
+        if tail[tail.len() - k..] == head[..k] {
+            return k;
+        }
+    }
+    0
+}
+
+/// Format a second offset as `HH:MM:SS`.
+fn format_timestamp(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!(
+        "{:02}:{:02}:{:02}",
+        total / 3600,
+        (total % 3600) / 60,
+        total % 60
+    )
+}
+
 fn ensure_chunk_within_limit(path: &Path) -> Result<()> {
     let metadata = std::fs::metadata(path)
         .with_context(|| format!("failed to read metadata for '{}'", path.display()))?;
@@ -283,32 +886,156 @@ fn load_openai_api_key() -> Result<String> {
     Ok(value)
 }
 
-fn transcribe_chunk_with_openai(chunk_path: &Path, api_key: String) -> Result<String> {
-    let file_name = chunk_path
-        .file_name()
-        .ok_or_else(|| anyhow!("chunk file '{}' has no valid name", chunk_path.display()))?
-        .to_string_lossy()
-        .to_string();
+/// A transcription engine that turns one audio chunk into text. Implementors carry their own model,
+/// language, and temperature configuration so callers stay backend-agnostic.
+#[allow(async_fn_in_trait)]
+trait Transcriber {
+    async fn transcribe(&self, chunk: &Path) -> Result<String>;
+}
 
-    let bytes = std::fs::read(chunk_path)
-        .with_context(|| format!("failed to read '{}'", chunk_path.display()))?;
+/// Concrete backends, dispatched as an enum in the spirit of the swappable codec selection above.
+enum Transcribers {
+    OpenAi(OpenAiBackend),
+    Whisper(WhisperBackend),
+}
 
-    let runtime = Runtime::new().context("failed to start tokio runtime")?;
-    runtime
-        .block_on(async move {
-            let config = OpenAIConfig::new().with_api_key(api_key);
-            let client = Client::with_config(config);
+impl Transcriber for Transcribers {
+    async fn transcribe(&self, chunk: &Path) -> Result<String> {
+        match self {
+            Transcribers::OpenAi(backend) => backend.transcribe(chunk).await,
+            Transcribers::Whisper(backend) => backend.transcribe(chunk).await,
+        }
+    }
+}
 
-            let request = CreateTranscriptionRequestArgs::default()
-                .model("gpt-4o-transcribe")
-                .file(AudioInput::from_vec_u8(file_name, bytes))
-                .build()?;
+/// The hosted OpenAI API, or any OpenAI-compatible endpoint when `base_url` is set.
+struct OpenAiBackend {
+    api_key: String,
+    base_url: Option<String>,
+    model: String,
+    language: Option<String>,
+    temperature: Option<f32>,
+    max_memory_bytes: u64,
+}
 
-            let response = client.audio().transcription().create(request).await?;
+impl Transcriber for OpenAiBackend {
+    async fn transcribe(&self, chunk: &Path) -> Result<String> {
+        let audio = build_audio_input(chunk, self.max_memory_bytes)?;
 
-            Ok::<_, async_openai::error::OpenAIError>(response.text)
-        })
-        .context("failed to run transcription request on the async runtime")
+        let mut config = OpenAIConfig::new().with_api_key(self.api_key.clone());
+        if let Some(base_url) = &self.base_url {
+            config = config.with_api_base(base_url.clone());
+        }
+        let client = Client::with_config(config);
+
+        let mut builder = CreateTranscriptionRequestArgs::default();
+        builder.model(&self.model).file(audio);
+        if let Some(language) = &self.language {
+            builder.language(language);
+        }
+        if let Some(temperature) = self.temperature {
+            builder.temperature(temperature);
+        }
+        let request = builder.build()?;
+
+        let response = client.audio().transcription().create(request).await?;
+        Ok(response.text)
+    }
+}
+
+/// A local `whisper`/`whisper.cpp` CLI, shelled out like `ffmpeg`/`ffprobe` and read from stdout.
+struct WhisperBackend {
+    binary: String,
+    model: Option<String>,
+    language: Option<String>,
+}
+
+impl Transcriber for WhisperBackend {
+    async fn transcribe(&self, chunk: &Path) -> Result<String> {
+        let mut command = Command::new(&self.binary);
+        command.arg("-f").arg(chunk).arg("-nt");
+        if let Some(model) = &self.model {
+            command.arg("-m").arg(model);
+        }
+        if let Some(language) = &self.language {
+            command.arg("-l").arg(language);
+        }
+
+        let output = command
+            .output()
+            .with_context(|| format!("failed to run '{}', is it installed?", self.binary))?;
+        if !output.status.success() {
+            bail!(
+                "{} returned a non-zero status while transcribing:\n{}",
+                self.binary,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+/// Build the transcriber selected on the command line, wiring per-backend configuration.
+#[allow(clippy::too_many_arguments)]
+fn build_transcriber(
+    backend: Backend,
+    model: Option<String>,
+    language: Option<String>,
+    temperature: Option<f32>,
+    base_url: Option<String>,
+    whisper_bin: String,
+    max_memory_bytes: u64,
+) -> Result<Transcribers> {
+    match backend {
+        Backend::Openai => Ok(Transcribers::OpenAi(OpenAiBackend {
+            api_key: load_openai_api_key()?,
+            base_url: None,
+            model: model.unwrap_or_else(|| "gpt-4o-transcribe".to_string()),
+            language,
+            temperature,
+            max_memory_bytes,
+        })),
+        Backend::Custom => {
+            let base_url = base_url
+                .ok_or_else(|| anyhow!("the custom backend requires --base-url"))?;
+            Ok(Transcribers::OpenAi(OpenAiBackend {
+                api_key: load_openai_api_key()?,
+                base_url: Some(base_url),
+                model: model.unwrap_or_else(|| "gpt-4o-transcribe".to_string()),
+                language,
+                temperature,
+                max_memory_bytes,
+            }))
+        }
+        Backend::Whisper => Ok(Transcribers::Whisper(WhisperBackend {
+            binary: whisper_bin,
+            model,
+            language,
+        })),
+    }
+}
+
+/// Build the multipart audio source for a chunk without slurping it into an owned `Vec<u8>`.
+///
+/// A size-checked (fallible) `metadata` read rejects any chunk whose footprint would exceed the
+/// `--max-memory-mb` budget with an explicit error instead of risking an allocator abort, and the
+/// request is then fed from a streaming file source so peak memory stays bounded.
+fn build_audio_input(chunk: &Path, max_memory_bytes: u64) -> Result<AudioInput> {
+    let size = std::fs::metadata(chunk)
+        .with_context(|| format!("failed to read metadata for '{}'", chunk.display()))?
+        .len();
+    if size > max_memory_bytes {
+        bail!(
+            "chunk '{}' needs {} bytes, exceeding the --max-memory-mb budget of {} bytes; \
+             raise --max-memory-mb or split the chunk first",
+            chunk.to_string_lossy(),
+            size,
+            max_memory_bytes
+        );
+    }
+
+    Ok(AudioInput::from(chunk.to_path_buf()))
 }
 
 fn transcript_output_path(input: &Path) -> PathBuf {
@@ -433,15 +1160,8 @@ fn calculate_equal_split_plan(duration_seconds: f64, parts: usize) -> Result<Vec
     Ok(plan)
 }
 
-/// Calculate start timestamps and durations so each chunk stays within the maximum size (in megabytes).
-pub fn calculate_chunk_plan(
-    duration_seconds: f64,
-    bitrate_kbps: f64,
-    max_size_mb: f64,
-) -> Result<Vec<(f64, f64)>> {
-    if duration_seconds <= 0.0 {
-        bail!("duration_seconds must be greater than zero");
-    }
+/// Derive the largest chunk duration that keeps a chunk within both the size and transcription limits.
+fn planned_chunk_duration(bitrate_kbps: f64, max_size_mb: f64) -> Result<f64> {
     if bitrate_kbps <= 0.0 {
         bail!("bitrate_kbps must be greater than zero");
     }
@@ -461,25 +1181,176 @@ pub fn calculate_chunk_plan(
         bail!("calculated chunk duration is less than one second; adjust inputs");
     }
 
+    Ok(chunk_duration)
+}
+
+/// Calculate start timestamps and durations so each chunk stays within the maximum size (in megabytes).
+///
+/// `overlap` makes consecutive windows overlap by that many seconds (the start advances by
+/// `chunk_duration - overlap`), which lets a later [`Stitch`](Commands::Stitch) pass recover words
+/// clipped at a cut. Pass `0.0` for back-to-back windows.
+pub fn calculate_chunk_plan(
+    duration_seconds: f64,
+    bitrate_kbps: f64,
+    max_size_mb: f64,
+    overlap: f64,
+) -> Result<Vec<(f64, f64)>> {
+    if duration_seconds <= 0.0 {
+        bail!("duration_seconds must be greater than zero");
+    }
+
+    let chunk_duration = planned_chunk_duration(bitrate_kbps, max_size_mb)?;
+    let step = chunk_advance(chunk_duration, overlap)?;
+
     let mut plan = Vec::new();
     let mut start = 0.0;
     while start < duration_seconds {
         let remaining = duration_seconds - start;
         let duration = chunk_duration.min(remaining);
         plan.push((start, duration));
-        start += duration;
+        start += step;
+    }
+
+    Ok(plan)
+}
+
+/// Validate `overlap` against `chunk_duration` and return how far each window's start advances.
+fn chunk_advance(chunk_duration: f64, overlap: f64) -> Result<f64> {
+    if overlap < 0.0 {
+        bail!("overlap must not be negative");
+    }
+    if overlap >= chunk_duration {
+        bail!("overlap must be smaller than the chunk duration");
+    }
+    Ok(chunk_duration - overlap)
+}
+
+/// A silent interval detected by ffmpeg's `silencedetect` filter, in seconds.
+#[derive(Debug, Clone, Copy)]
+struct SilenceInterval {
+    start: f64,
+    end: f64,
+}
+
+impl SilenceInterval {
+    fn midpoint(&self) -> f64 {
+        (self.start + self.end) / 2.0
+    }
+}
+
+/// Like [`calculate_chunk_plan`], but nudge each cut back to the nearest detected silence so a
+/// boundary never lands mid-word. The budget-derived cut is the upper bound; a silence is only
+/// used when its midpoint sits within `max_drift` seconds at or before that cut.
+fn calculate_chunk_plan_snapped(
+    duration_seconds: f64,
+    bitrate_kbps: f64,
+    max_size_mb: f64,
+    silences: &[SilenceInterval],
+    max_drift: f64,
+    overlap: f64,
+) -> Result<Vec<(f64, f64)>> {
+    if duration_seconds <= 0.0 {
+        bail!("duration_seconds must be greater than zero");
+    }
+    if max_drift < 0.0 {
+        bail!("max_drift must not be negative");
+    }
+
+    let chunk_duration = planned_chunk_duration(bitrate_kbps, max_size_mb)?;
+    chunk_advance(chunk_duration, overlap)?;
+
+    let mut plan = Vec::new();
+    let mut start = 0.0;
+    while start < duration_seconds {
+        let ideal = start + chunk_duration;
+        if ideal >= duration_seconds {
+            plan.push((start, duration_seconds - start));
+            break;
+        }
+
+        let cut = best_silence_cut(silences, start, ideal, max_drift).unwrap_or(ideal);
+        plan.push((start, cut - start));
+        // Rewind the next start by the overlap so consecutive windows share audio.
+        start = (cut - overlap).max(start + 1.0);
     }
 
     Ok(plan)
 }
 
+/// Find the latest silence midpoint strictly after `start`, at or before `ideal`, and within
+/// `max_drift` of `ideal`. Returns `None` when no silence qualifies (the caller falls back to `ideal`).
+fn best_silence_cut(
+    silences: &[SilenceInterval],
+    start: f64,
+    ideal: f64,
+    max_drift: f64,
+) -> Option<f64> {
+    silences
+        .iter()
+        .map(SilenceInterval::midpoint)
+        .filter(|&m| m > start && m <= ideal && ideal - m <= max_drift)
+        .reduce(f64::max)
+}
+
+/// Parse the `silence_start`/`silence_end` timestamps ffmpeg's `silencedetect` writes to stderr.
+fn parse_silences(stderr: &str) -> Vec<SilenceInterval> {
+    let mut intervals = Vec::new();
+    let mut pending_start: Option<f64> = None;
+
+    for line in stderr.lines() {
+        if let Some(rest) = line.split("silence_start:").nth(1) {
+            if let Some(value) = rest.split_whitespace().next() {
+                if let Ok(start) = value.parse::<f64>() {
+                    pending_start = Some(start);
+                }
+            }
+        } else if let Some(rest) = line.split("silence_end:").nth(1) {
+            if let Some(value) = rest.split_whitespace().next() {
+                if let (Ok(end), Some(start)) = (value.parse::<f64>(), pending_start.take()) {
+                    intervals.push(SilenceInterval { start, end });
+                }
+            }
+        }
+    }
+
+    intervals
+}
+
+/// Run `silencedetect` over the input and return the silent intervals it reports.
+fn detect_silences(input: &Path, noise_db: f64, min_silence: f64) -> Result<Vec<SilenceInterval>> {
+    let filter = format!("silencedetect=noise={noise_db}dB:d={min_silence}");
+    let output = Command::new("ffmpeg")
+        .arg("-hide_banner")
+        .arg("-i")
+        .arg(input)
+        .arg("-af")
+        .arg(&filter)
+        .arg("-f")
+        .arg("null")
+        .arg("-")
+        .output()
+        .with_context(|| "failed to run ffmpeg, is it installed and on PATH?")?;
+
+    if !output.status.success() {
+        bail!(
+            "ffmpeg returned a non-zero status while detecting silence:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(parse_silences(&String::from_utf8_lossy(&output.stderr)))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{calculate_chunk_plan, calculate_equal_split_plan};
+    use super::{
+        SilenceInterval, calculate_chunk_plan, calculate_chunk_plan_snapped,
+        calculate_equal_split_plan, parse_silences, retry_backoff, stitch_transcripts,
+    };
 
     #[test]
     fn splits_into_expected_chunk_lengths() {
-        let plan = calculate_chunk_plan(3600.0, 228.0, 25.0).unwrap();
+        let plan = calculate_chunk_plan(3600.0, 228.0, 25.0, 0.0).unwrap();
         assert_eq!(plan.len(), 5);
         assert!((plan[0].0 - 0.0).abs() < 1e-6);
         assert!((plan[0].1 - 864.0).abs() < 1e-6);
@@ -491,14 +1362,14 @@ mod tests {
 
     #[test]
     fn rejects_invalid_inputs() {
-        assert!(calculate_chunk_plan(0.0, 228.0, 25.0).is_err());
-        assert!(calculate_chunk_plan(10.0, 0.0, 25.0).is_err());
-        assert!(calculate_chunk_plan(10.0, 228.0, 0.0).is_err());
+        assert!(calculate_chunk_plan(0.0, 228.0, 25.0, 0.0).is_err());
+        assert!(calculate_chunk_plan(10.0, 0.0, 25.0, 0.0).is_err());
+        assert!(calculate_chunk_plan(10.0, 228.0, 0.0, 0.0).is_err());
     }
 
     #[test]
     fn caps_chunk_duration_at_transcription_limit() {
-        let plan = calculate_chunk_plan(4000.0, 128.0, 25.0).unwrap();
+        let plan = calculate_chunk_plan(4000.0, 128.0, 25.0, 0.0).unwrap();
         assert_eq!(plan.len(), 4);
         assert!((plan[0].1 - 1300.0).abs() < 1e-6);
         assert!((plan[1].1 - 1300.0).abs() < 1e-6);
@@ -523,4 +1394,90 @@ mod tests {
         assert!(calculate_equal_split_plan(0.0, 3).is_err());
         assert!(calculate_equal_split_plan(10.0, 1).is_err());
     }
+
+    #[test]
+    fn retry_backoff_grows_exponentially_then_saturates() {
+        assert_eq!(retry_backoff(1).as_millis(), 500);
+        assert_eq!(retry_backoff(2).as_millis(), 1000);
+        assert_eq!(retry_backoff(3).as_millis(), 2000);
+        // The exponent saturates so a large retry budget cannot overflow the delay.
+        assert_eq!(retry_backoff(7).as_millis(), retry_backoff(50).as_millis());
+    }
+
+    #[test]
+    fn parses_silence_intervals_from_ffmpeg_stderr() {
+        let stderr = "\
+[silencedetect @ 0x1] silence_start: 10.5
+[silencedetect @ 0x1] silence_end: 11.25 | silence_duration: 0.75
+[silencedetect @ 0x1] silence_start: 42
+[silencedetect @ 0x1] silence_end: 43.0 | silence_duration: 1.0
+";
+        let intervals = parse_silences(stderr);
+        assert_eq!(intervals.len(), 2);
+        assert!((intervals[0].start - 10.5).abs() < 1e-6);
+        assert!((intervals[0].end - 11.25).abs() < 1e-6);
+        assert!((intervals[1].start - 42.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn snaps_cut_to_nearby_silence_within_drift() {
+        // Budget yields ~864 s chunks; a silence just before the first cut should win.
+        let silences = vec![
+            SilenceInterval {
+                start: 860.0,
+                end: 862.0,
+            },
+            // Too far after the ideal cut to matter for the first boundary.
+            SilenceInterval {
+                start: 900.0,
+                end: 902.0,
+            },
+        ];
+        let plan = calculate_chunk_plan_snapped(3600.0, 228.0, 25.0, &silences, 5.0, 0.0).unwrap();
+        // First cut snaps to the 860-862 midpoint (861) rather than 864.
+        assert!((plan[0].1 - 861.0).abs() < 1e-6);
+        assert!((plan[1].0 - 861.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn falls_back_to_budget_cut_when_no_silence_is_close() {
+        // Only silence is far outside the drift window, so the plan matches the plain budget plan.
+        let silences = vec![SilenceInterval {
+            start: 10.0,
+            end: 11.0,
+        }];
+        let snapped = calculate_chunk_plan_snapped(3600.0, 228.0, 25.0, &silences, 5.0, 0.0).unwrap();
+        let plain = calculate_chunk_plan(3600.0, 228.0, 25.0, 0.0).unwrap();
+        assert_eq!(snapped.len(), plain.len());
+        assert!((snapped[0].1 - plain[0].1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn overlap_makes_windows_share_audio() {
+        let plan = calculate_chunk_plan(3600.0, 228.0, 25.0, 64.0).unwrap();
+        // Each window is still 864 s, but starts advance by 864 - 64 = 800 s.
+        assert!((plan[0].1 - 864.0).abs() < 1e-6);
+        assert!((plan[1].0 - 800.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn stitch_deduplicates_overlap_region() {
+        let segments = vec![
+            (0.0, "the quick brown fox jumps".to_string()),
+            (10.0, "brown fox jumps over the lazy dog".to_string()),
+        ];
+        // Overlap window large enough to catch the repeated "brown fox jumps".
+        let merged = stitch_transcripts(&segments, 4.0, false);
+        assert_eq!(merged, "the quick brown fox jumps\nover the lazy dog");
+    }
+
+    #[test]
+    fn stitch_can_prefix_timestamps() {
+        let segments = vec![
+            (0.0, "hello world".to_string()),
+            (3661.0, "goodbye".to_string()),
+        ];
+        let merged = stitch_transcripts(&segments, 0.0, true);
+        assert_eq!(merged, "[00:00:00] hello world\n[01:01:01] goodbye");
+    }
 }